@@ -0,0 +1,28 @@
+use crate::{PpsFile, PpsInfo, Result};
+use tokio::io::unix::AsyncFd;
+
+/// Async adapter over [`PpsFile`] built on tokio's [`AsyncFd`], letting a PPS
+/// device be driven from an async runtime instead of a dedicated blocking
+/// thread.
+pub struct AsyncPpsFile {
+    inner: AsyncFd<PpsFile>,
+}
+
+impl AsyncPpsFile {
+    pub fn new(pps_file: PpsFile) -> Result<Self> {
+        Ok(Self {
+            inner: AsyncFd::new(pps_file)?,
+        })
+    }
+
+    /// Awaits the next PPS edge, retrying on spurious readiness wakeups.
+    pub async fn next_event(&self) -> Result<PpsInfo> {
+        loop {
+            let mut guard = self.inner.readable().await?;
+            match guard.get_inner().try_fetch()? {
+                Some(info) => return Ok(info),
+                None => guard.clear_ready(),
+            }
+        }
+    }
+}
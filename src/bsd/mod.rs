@@ -0,0 +1,313 @@
+use super::common::{ioctl_none, ioctl_read, ioctl_readwrite, ioctl_write_ptr, PpsIoc};
+use crate::{PpsInfo, PpsMode, PpsModeBit, PpsParams, PpsTimeU, PpsVersion};
+use nix::{libc::c_int, sys::time::TimeSpec, Result};
+use std::collections::HashMap;
+use std::os::fd::RawFd;
+use std::time::Duration;
+
+const PPS_MAGIC: u8 = b'1';
+const PPS_IOC_CREATE: u8 = 1;
+const PPS_IOC_DESTROY: u8 = 2;
+const PPS_IOC_SETPARAMS: u8 = 3;
+const PPS_IOC_GETPARAMS: u8 = 4;
+const PPS_IOC_GETCAP: u8 = 5;
+const PPS_IOC_FETCH: u8 = 6;
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BsdTimeSpec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+impl Default for BsdTimeSpec {
+    /// Block-forever sentinel used in place of a NULL timeout.
+    fn default() -> Self {
+        Self {
+            tv_sec: -1,
+            tv_nsec: -1,
+        }
+    }
+}
+
+impl BsdTimeSpec {
+    pub(crate) fn non_blocking() -> Self {
+        Self {
+            tv_sec: 0,
+            tv_nsec: 0,
+        }
+    }
+}
+
+impl From<BsdTimeSpec> for TimeSpec {
+    fn from(value: BsdTimeSpec) -> Self {
+        TimeSpec::new(value.tv_sec, value.tv_nsec)
+    }
+}
+
+impl From<TimeSpec> for BsdTimeSpec {
+    fn from(value: TimeSpec) -> Self {
+        Self {
+            tv_sec: value.tv_sec(),
+            tv_nsec: value.tv_nsec(),
+        }
+    }
+}
+
+impl From<Duration> for BsdTimeSpec {
+    fn from(value: Duration) -> Self {
+        let ts: TimeSpec = value.into();
+        ts.into()
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union BsdPpsTime {
+    pub tspec: BsdTimeSpec,
+}
+
+impl Default for BsdPpsTime {
+    fn default() -> Self {
+        Self {
+            tspec: BsdTimeSpec::default(),
+        }
+    }
+}
+
+impl From<PpsTimeU> for BsdPpsTime {
+    fn from(value: PpsTimeU) -> Self {
+        match value {
+            PpsTimeU::TimeSpec(ts) => Self { tspec: ts.into() },
+            PpsTimeU::NtpFp(ntp) => {
+                let ts: TimeSpec = ntp.into();
+                Self { tspec: ts.into() }
+            }
+        }
+    }
+}
+
+fn get_tus_from_pps_time(
+    mode: PpsMode,
+    assert_tu: BsdPpsTime,
+    clear_tu: BsdPpsTime,
+) -> (PpsTimeU, PpsTimeU) {
+    assert!(mode.mode_is_set(PpsModeBit::TsFmtTSpec));
+    let assert_tu_ts;
+    let clear_tu_ts;
+    unsafe {
+        assert_tu_ts = assert_tu.tspec;
+        clear_tu_ts = clear_tu.tspec;
+    }
+    let assert_tu = PpsTimeU::TimeSpec(assert_tu_ts.into());
+    let clear_tu = PpsTimeU::TimeSpec(clear_tu_ts.into());
+    (assert_tu, clear_tu)
+}
+
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct BsdPpsInfo {
+    pub assert_sequence: u32,
+    pub clear_sequence: u32,
+    pub assert_tu: BsdPpsTime,
+    pub clear_tu: BsdPpsTime,
+    pub current_mode: c_int,
+}
+
+impl From<BsdPpsInfo> for PpsInfo {
+    fn from(value: BsdPpsInfo) -> Self {
+        let (assert_tu, clear_tu) =
+            get_tus_from_pps_time(value.current_mode.into(), value.assert_tu, value.clear_tu);
+        Self {
+            assert_sequence: value.assert_sequence as u64,
+            assert_tu,
+            clear_sequence: value.clear_sequence as u64,
+            clear_tu,
+            mode: value.current_mode.into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct BsdPpsParams {
+    pub api_version: c_int,
+    pub mode: c_int,
+    pub assert_off_tu: BsdPpsTime,
+    pub clear_off_tu: BsdPpsTime,
+}
+
+impl BsdPpsParams {
+    pub fn new(
+        api_version: c_int,
+        mode: c_int,
+        assert_off_tu: BsdPpsTime,
+        clear_off_tu: BsdPpsTime,
+    ) -> Self {
+        Self {
+            api_version,
+            mode,
+            assert_off_tu,
+            clear_off_tu,
+        }
+    }
+}
+
+impl From<BsdPpsParams> for PpsParams {
+    fn from(value: BsdPpsParams) -> Self {
+        let (assert_off_tu, clear_off_tu) =
+            get_tus_from_pps_time(value.mode.into(), value.assert_off_tu, value.clear_off_tu);
+        Self {
+            api_version: PpsVersion::new(value.api_version),
+            mode: value.mode.into(),
+            assert_off_tu,
+            clear_off_tu,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct BsdPpsFetchArgs {
+    pub info: BsdPpsInfo,
+    pub timeout: BsdTimeSpec,
+}
+
+ioctl_none!(bsd_create_pps, PPS_MAGIC, PPS_IOC_CREATE);
+ioctl_none!(bsd_destroy_pps, PPS_MAGIC, PPS_IOC_DESTROY);
+ioctl_read!(bsd_get_pps_params, PPS_MAGIC, PPS_IOC_GETPARAMS, BsdPpsParams);
+ioctl_write_ptr!(
+    bsd_set_pps_params,
+    PPS_MAGIC,
+    PPS_IOC_SETPARAMS,
+    BsdPpsParams
+);
+ioctl_read!(bsd_get_pps_cap, PPS_MAGIC, PPS_IOC_GETCAP, i32);
+ioctl_readwrite!(
+    bsd_fetch_pps_info,
+    PPS_MAGIC,
+    PPS_IOC_FETCH,
+    BsdPpsFetchArgs
+);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct BsdPpsIoc;
+
+impl PpsIoc for BsdPpsIoc {
+    fn create(fd: RawFd) -> Result<()> {
+        unsafe {
+            bsd_create_pps(fd)?;
+        }
+        Ok(())
+    }
+    fn destroy(fd: RawFd) -> Result<()> {
+        unsafe {
+            bsd_destroy_pps(fd)?;
+        }
+        Ok(())
+    }
+
+    fn get_params(fd: RawFd) -> Result<BsdPpsParams> {
+        let mut params = BsdPpsParams::default();
+        let _res;
+        unsafe {
+            _res = bsd_get_pps_params(fd, &mut params)?;
+        }
+        Ok(params)
+    }
+    fn set_params(
+        fd: RawFd,
+        assert_offset: PpsTimeU,
+        clear_offset: PpsTimeU,
+        api_version: PpsVersion,
+        mode: PpsMode,
+    ) -> Result<()> {
+        let params = BsdPpsParams::new(
+            api_version.into(),
+            mode.into(),
+            assert_offset.into(),
+            clear_offset.into(),
+        );
+        let _res;
+        unsafe {
+            _res = bsd_set_pps_params(fd, &params)?;
+        }
+        Ok(())
+    }
+    fn get_cap(fd: RawFd) -> Result<HashMap<PpsModeBit, bool>> {
+        let mut ffi_cap: c_int = 0;
+        let cap: PpsMode;
+        let _res;
+        unsafe {
+            _res = bsd_get_pps_cap(fd, &mut ffi_cap)?;
+        }
+        cap = ffi_cap.into();
+        Ok(cap.get_bits())
+    }
+    fn fetch(fd: RawFd, timeout: BsdTimeSpec) -> Result<BsdPpsInfo> {
+        let mut fetch_args = BsdPpsFetchArgs::default();
+        fetch_args.timeout = timeout;
+        let _res;
+        unsafe {
+            _res = bsd_fetch_pps_info(fd, &mut fetch_args)?;
+        }
+        Ok(fetch_args.info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW_TV_SEC: i64 = 1_700_000_000;
+    const RAW_TV_NSEC: i64 = 123_456_789;
+
+    fn raw_ktime() -> BsdTimeSpec {
+        BsdTimeSpec {
+            tv_sec: RAW_TV_SEC,
+            tv_nsec: RAW_TV_NSEC,
+        }
+    }
+
+    #[test]
+    fn time_spec_round_trips() {
+        let decoded: TimeSpec = raw_ktime().into();
+        assert_eq!(decoded.tv_sec(), RAW_TV_SEC);
+        assert_eq!(decoded.tv_nsec(), RAW_TV_NSEC);
+        let encoded: BsdTimeSpec = decoded.into();
+        assert_eq!(encoded.tv_sec, RAW_TV_SEC);
+        assert_eq!(encoded.tv_nsec, RAW_TV_NSEC);
+    }
+
+    #[test]
+    fn default_blocks_forever_while_non_blocking_is_zero() {
+        assert_ne!(BsdTimeSpec::default().tv_sec, BsdTimeSpec::non_blocking().tv_sec);
+        assert_eq!(BsdTimeSpec::non_blocking().tv_sec, 0);
+        assert_eq!(BsdTimeSpec::non_blocking().tv_nsec, 0);
+    }
+
+    #[test]
+    fn pps_info_conversion_preserves_sequence_and_timestamps() {
+        let raw_info = BsdPpsInfo {
+            assert_sequence: 7,
+            clear_sequence: 8,
+            assert_tu: BsdPpsTime { tspec: raw_ktime() },
+            clear_tu: BsdPpsTime {
+                tspec: BsdTimeSpec::non_blocking(),
+            },
+            current_mode: PpsModeBit::TsFmtTSpec as i32,
+        };
+
+        let info: PpsInfo = raw_info.into();
+
+        assert_eq!(info.assert_sequence, 7);
+        assert_eq!(info.clear_sequence, 8);
+        match info.assert_tu {
+            PpsTimeU::TimeSpec(ts) => {
+                assert_eq!(ts.tv_sec(), RAW_TV_SEC);
+                assert_eq!(ts.tv_nsec(), RAW_TV_NSEC);
+            }
+            PpsTimeU::NtpFp(_) => panic!("expected PpsTimeU::TimeSpec, got PpsTimeU::NtpFp"),
+        }
+    }
+}
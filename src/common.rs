@@ -13,6 +13,16 @@ pub(crate) use crate::linux::{
     LinuxPpsInfo as PpsInfoFfi, LinuxPpsIoc as PpsIocImpl, LinuxPpsParams as PpsParamsFfi,
     LinuxTimeSpec as TimeSpecFfi,
 };
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos"
+))]
+pub(crate) use crate::bsd::{
+    BsdPpsInfo as PpsInfoFfi, BsdPpsIoc as PpsIocImpl, BsdPpsParams as PpsParamsFfi,
+    BsdTimeSpec as TimeSpecFfi,
+};
 use crate::PpsTimeU;
 
 #[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
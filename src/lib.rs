@@ -1,9 +1,12 @@
+use nix::errno::Errno;
+use nix::libc::c_int;
+use nix::poll::{poll, PollFd, PollFlags};
 use nix::sys::time::TimeSpec;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::fs::{File, OpenOptions};
 use std::io::Error as IoError;
-use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::fd::{AsFd, AsRawFd, FromRawFd, OwnedFd, RawFd};
 use std::path::Path;
 use std::time::Duration;
 use thiserror::Error;
@@ -13,8 +16,19 @@ pub type Result<T> = std::result::Result<T, PpsError>;
 mod common;
 #[cfg(any(target_os = "linux"))]
 mod linux;
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos"
+))]
+mod bsd;
+#[cfg(feature = "async")]
+mod async_pps;
 
 pub use crate::common::*;
+#[cfg(feature = "async")]
+pub use crate::async_pps::AsyncPpsFile;
 
 const UNIX_NTP_OFFSET: i64 = 3124137599 - 915148799;
 
@@ -81,9 +95,45 @@ impl PpsFile {
             timeout.into()
         };
 
+        self.fetch_raw(timeout_ffi)
+    }
+
+    fn fetch_raw(&self, timeout_ffi: TimeSpecFfi) -> Result<PpsInfo> {
         let raw_fetch = PpsIocImpl::fetch(self.pps_device.as_raw_fd(), timeout_ffi)?;
         Ok(raw_fetch.into())
     }
+
+    /// Polls the device for a pending PPS edge, returning as soon as `POLLIN`
+    /// is ready (or the timeout, if given, elapses). A `None` timeout blocks
+    /// indefinitely, matching the `PPS_CANPOLL` capability advertised by
+    /// `get_cap`.
+    pub fn poll_ready(&self, timeout: Option<Duration>) -> Result<bool> {
+        let timeout_ms: c_int = match timeout {
+            Some(duration) => duration.as_millis().try_into().unwrap_or(c_int::MAX),
+            None => -1,
+        };
+        let mut fds = [PollFd::new(self.pps_device.as_fd(), PollFlags::POLLIN)];
+        let n_ready = poll(&mut fds, timeout_ms)?;
+        Ok(n_ready > 0)
+    }
+
+    /// Non-blocking variant of [`Self::fetch`]: issues the fetch with a
+    /// genuinely zero (not the "block indefinitely" sentinel `fetch` uses
+    /// for `Duration::ZERO`) timeout, returning `Ok(None)` instead of an
+    /// error if no edge was already pending.
+    pub fn try_fetch(&self) -> Result<Option<PpsInfo>> {
+        match self.fetch_raw(TimeSpecFfi::non_blocking()) {
+            Ok(info) => Ok(Some(info)),
+            Err(PpsError::Sys(Errno::EAGAIN)) | Err(PpsError::Sys(Errno::ETIMEDOUT)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl AsRawFd for PpsFile {
+    fn as_raw_fd(&self) -> RawFd {
+        self.pps_device.as_raw_fd()
+    }
 }
 
 impl TryFrom<&Path> for PpsFile {
@@ -1,5 +1,5 @@
 use super::common::{ioctl_read, ioctl_readwrite, ioctl_write_ptr, PpsIoc};
-use crate::{PpsInfo, PpsMode, PpsModeBit, PpsParams, PpsTimeU, PpsVersion};
+use crate::{NtpFp, PpsInfo, PpsMode, PpsModeBit, PpsParams, PpsTimeU, PpsVersion};
 use nix::{libc::c_int, sys::time::TimeSpec, Result};
 use std::collections::HashMap;
 use std::os::fd::RawFd;
@@ -11,6 +11,9 @@ const PPS_IOC_SETPARAMS: u8 = 0xA2;
 const PPS_IOC_GETCAP: u8 = 0xA3;
 const PPS_IOC_FETCH: u8 = 0xA4;
 
+/// Mirrors the kernel's `struct pps_ktime`. `tv_sec` is carried as `i64`
+/// (never the platform `long`) so that timestamps past the 2038 rollover
+/// survive unchanged on 32-bit userspace talking to a 64-bit kernel.
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub struct LinuxTimeSpec {
@@ -19,6 +22,32 @@ pub struct LinuxTimeSpec {
     pub flags: u32,
 }
 
+/// No 32-bit compat struct or alternate `PPS_IOC_*` request-code selection
+/// exists here. Every field in `LinuxTimeSpec`, `LinuxPpsInfo`,
+/// `LinuxPpsParams`, and `LinuxPpsFetchArgs` is explicitly sized
+/// (`i64`/`i32`/`u32`/`c_int`), never the platform `long`, so
+/// `LinuxTimeSpec` is 16 bytes on every target. The trailing padding of
+/// `LinuxPpsInfo` still follows the target's `i64` alignment: it is 44 bytes
+/// on i686 and 48 bytes on armv7 and 64-bit targets, and the request codes
+/// the `ioctl_*!` macros derive from `size_of` differ accordingly. The
+/// assertions below pin these sizes so a future field change can't silently
+/// reintroduce a platform-`long`.
+#[cfg(target_pointer_width = "32")]
+const _: () = {
+    assert!(std::mem::size_of::<LinuxTimeSpec>() == 16);
+    const INFO_FIELDS: usize =
+        2 * std::mem::size_of::<i32>() + 2 * std::mem::size_of::<LinuxPpsTime>() + 4;
+    const INFO_ALIGN: usize = std::mem::align_of::<LinuxPpsInfo>();
+    assert!(
+        std::mem::size_of::<LinuxPpsInfo>()
+            == (INFO_FIELDS + INFO_ALIGN - 1) / INFO_ALIGN * INFO_ALIGN
+    );
+    assert!(
+        std::mem::size_of::<LinuxPpsFetchArgs>()
+            == std::mem::size_of::<LinuxPpsInfo>() + std::mem::size_of::<LinuxTimeSpec>()
+    );
+};
+
 impl Default for LinuxTimeSpec {
     fn default() -> Self {
         Self {
@@ -29,6 +58,17 @@ impl Default for LinuxTimeSpec {
     }
 }
 
+impl LinuxTimeSpec {
+    /// `flags: 0`, unlike `Default`'s `flags: 1` (`PPS_TIME_INVALID`).
+    pub(crate) fn non_blocking() -> Self {
+        Self {
+            tv_sec: 0,
+            tv_nsec: 0,
+            flags: 0,
+        }
+    }
+}
+
 impl From<LinuxTimeSpec> for TimeSpec {
     fn from(value: LinuxTimeSpec) -> Self {
         TimeSpec::new(value.tv_sec, value.tv_nsec as i64)
@@ -52,10 +92,36 @@ impl From<Duration> for LinuxTimeSpec {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct LinuxNtpFp {
+    pub integral: u32,
+    pub fractional: u32,
+}
+
+impl From<LinuxNtpFp> for NtpFp {
+    fn from(value: LinuxNtpFp) -> Self {
+        NtpFp {
+            integral: value.integral,
+            fractional: value.fractional,
+        }
+    }
+}
+
+impl From<NtpFp> for LinuxNtpFp {
+    fn from(value: NtpFp) -> Self {
+        Self {
+            integral: value.integral,
+            fractional: value.fractional,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub union LinuxPpsTime {
     pub tspec: LinuxTimeSpec,
+    pub ntpfp: LinuxNtpFp,
 }
 
 impl Default for LinuxPpsTime {
@@ -70,10 +136,7 @@ impl From<PpsTimeU> for LinuxPpsTime {
     fn from(value: PpsTimeU) -> Self {
         match value {
             PpsTimeU::TimeSpec(ts) => Self { tspec: ts.into() },
-            PpsTimeU::NtpFp(ntp) => {
-                let ts: TimeSpec = ntp.into();
-                Self { tspec: ts.into() }
-            }
+            PpsTimeU::NtpFp(ntp) => Self { ntpfp: ntp.into() },
         }
     }
 }
@@ -83,6 +146,18 @@ fn get_tus_from_pps_time(
     assert_tu: LinuxPpsTime,
     clear_tu: LinuxPpsTime,
 ) -> (PpsTimeU, PpsTimeU) {
+    if mode.mode_is_set(PpsModeBit::TsFmtNTPFP) {
+        let assert_tu_ntp;
+        let clear_tu_ntp;
+        unsafe {
+            assert_tu_ntp = assert_tu.ntpfp;
+            clear_tu_ntp = clear_tu.ntpfp;
+        }
+        let assert_tu = PpsTimeU::NtpFp(assert_tu_ntp.into());
+        let clear_tu = PpsTimeU::NtpFp(clear_tu_ntp.into());
+        return (assert_tu, clear_tu);
+    }
+
     assert!(mode.mode_is_set(PpsModeBit::TsFmtTSpec));
     let assert_tu_ts;
     let clear_tu_ts;
@@ -244,3 +319,74 @@ impl PpsIoc for LinuxPpsIoc {
         Ok(fetch_args.info)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Raw `pps_ktime` bytes for 2040-01-01T00:00:00.123456789Z, chosen to
+    // fall well past the 32-bit `time_t` rollover in 2038.
+    const RAW_TV_SEC: i64 = 2_208_988_800;
+    const RAW_TV_NSEC: i32 = 123_456_789;
+
+    fn raw_ktime() -> LinuxTimeSpec {
+        LinuxTimeSpec {
+            tv_sec: RAW_TV_SEC,
+            tv_nsec: RAW_TV_NSEC,
+            flags: 0,
+        }
+    }
+
+    #[test]
+    fn time_spec_survives_y2038_on_both_widths() {
+        let decoded: TimeSpec = raw_ktime().into();
+        assert_eq!(decoded.tv_sec(), RAW_TV_SEC);
+        assert_eq!(decoded.tv_nsec(), RAW_TV_NSEC as i64);
+    }
+
+    #[test]
+    fn ktime_layout_is_pointer_width_independent() {
+        assert_eq!(std::mem::size_of::<LinuxTimeSpec>(), 16);
+    }
+
+    #[test]
+    fn non_blocking_is_not_the_block_forever_sentinel() {
+        assert_ne!(LinuxTimeSpec::non_blocking().flags, LinuxTimeSpec::default().flags);
+        assert_eq!(LinuxTimeSpec::non_blocking().flags, 0);
+        assert_eq!(LinuxTimeSpec::default().flags, 1);
+    }
+
+    #[test]
+    fn ntp_fp_mode_decodes_to_ntp_fp_not_time_spec() {
+        let mode: PpsMode = (PpsModeBit::TsFmtNTPFP as i32).into();
+        let assert_tu = LinuxPpsTime {
+            ntpfp: LinuxNtpFp {
+                integral: 3_913_056_000,
+                fractional: 2_147_483_648,
+            },
+        };
+        let clear_tu = LinuxPpsTime {
+            ntpfp: LinuxNtpFp {
+                integral: 3_913_056_001,
+                fractional: 0,
+            },
+        };
+
+        let (assert_tu, clear_tu) = get_tus_from_pps_time(mode, assert_tu, clear_tu);
+
+        match assert_tu {
+            PpsTimeU::NtpFp(ntp) => {
+                assert_eq!(ntp.integral, 3_913_056_000);
+                assert_eq!(ntp.fractional, 2_147_483_648);
+            }
+            PpsTimeU::TimeSpec(_) => panic!("expected PpsTimeU::NtpFp, got PpsTimeU::TimeSpec"),
+        }
+        match clear_tu {
+            PpsTimeU::NtpFp(ntp) => {
+                assert_eq!(ntp.integral, 3_913_056_001);
+                assert_eq!(ntp.fractional, 0);
+            }
+            PpsTimeU::TimeSpec(_) => panic!("expected PpsTimeU::NtpFp, got PpsTimeU::TimeSpec"),
+        }
+    }
+}